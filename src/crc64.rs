@@ -0,0 +1,110 @@
+//! A CRC-64 engine configured from runtime [`Crc64Params`] rather than a compile-time model.
+
+use crate::generator::{bit_reverse, generate_key, generate_mu, generate_reciprocal_polynomial, KEY_SIZES};
+use crate::params::Crc64Params;
+use crate::scalar::{forward_update, reflected_update};
+
+/// A CRC-64 checksum engine for an arbitrary, runtime-supplied set of [`Crc64Params`].
+///
+/// [`Crc64::with_params`] precomputes the folding-constant table (the key table, \mu, and
+/// reciprocal polynomial) that an accelerated carryless-multiplication implementation would
+/// need, for a future SIMD backend to consume. No such backend is implemented in this crate
+/// yet: [`Crc64::digest`] always runs the portable, bit-at-a-time [`scalar`](crate::scalar)
+/// fallback, so these constants currently have no accelerated consumer.
+///
+/// For a non-reflected (`refin = false`) model, [`Crc64::keys`], [`Crc64::mu`], and
+/// [`Crc64::reciprocal`] hold [`crate::generator`]'s `reflected = false` output, which (per its
+/// doc comments) is not a valid forward Barrett constant — only a raw intermediate kept so the
+/// fields always have a value. [`Crc64::digest`] does not use them for either CRC direction.
+pub struct Crc64 {
+    params: Crc64Params,
+    keys: [u64; KEY_SIZES.len()],
+    mu: u64,
+    reciprocal: u64,
+}
+
+impl Crc64 {
+    /// Builds a `Crc64` engine for the given parameters, computing its folding-constant table.
+    pub fn with_params(params: Crc64Params) -> Self {
+        let mut keys = [0u64; KEY_SIZES.len()];
+        for (slot, &size) in keys.iter_mut().zip(KEY_SIZES.iter()) {
+            *slot = generate_key(64, size as u64, params.poly, params.refin);
+        }
+
+        Self {
+            params,
+            keys,
+            mu: generate_mu(64, params.poly, params.refin),
+            reciprocal: generate_reciprocal_polynomial(64, params.poly, params.refin),
+        }
+    }
+
+    /// The parameters this engine was built from.
+    pub fn params(&self) -> Crc64Params {
+        self.params
+    }
+
+    /// The precomputed folding-constant table, indexed the same way as [`crate::generator::KEY_SIZES`].
+    pub fn keys(&self) -> &[u64; KEY_SIZES.len()] {
+        &self.keys
+    }
+
+    /// The precomputed Barrett-reduction \mu constant.
+    pub fn mu(&self) -> u64 {
+        self.mu
+    }
+
+    /// The precomputed reciprocal polynomial.
+    pub fn reciprocal(&self) -> u64 {
+        self.reciprocal
+    }
+
+    /// Computes the CRC-64 checksum of `data` under this engine's parameters.
+    pub fn digest(&self, data: &[u8]) -> u64 {
+        let register = if self.params.refin {
+            let poly_rev = bit_reverse(self.params.poly);
+            reflected_update(self.params.init, poly_rev, data)
+        } else {
+            forward_update(self.params.init, self.params.poly, data)
+        };
+
+        let oriented = if self.params.refin == self.params.refout {
+            register
+        } else {
+            bit_reverse(register)
+        };
+
+        oriented ^ self.params.xorout
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digests_crc64_nvme_check_value() {
+        let crc = Crc64::with_params(Crc64Params::CRC64_NVME);
+        assert_eq!(crc.digest(b"123456789"), 0xae8b_1486_0a79_9888);
+    }
+
+    #[test]
+    fn digests_crc64_xz_check_value() {
+        let crc = Crc64::with_params(Crc64Params::CRC64_XZ);
+        assert_eq!(crc.digest(b"123456789"), 0x995d_c9bb_df19_39fa);
+    }
+
+    #[test]
+    fn digests_crc64_ecma_182_check_value() {
+        // CRC-64/ECMA-182 is non-reflected (refin = refout = false); digest() takes the
+        // `forward_update` path here, not `reflected_update`.
+        let crc = Crc64::with_params(Crc64Params::CRC64_ECMA_182);
+        assert_eq!(crc.digest(b"123456789"), 0x6c40_df5f_0b49_7347);
+    }
+
+    #[test]
+    fn key_table_matches_generator_output() {
+        let crc = Crc64::with_params(Crc64Params::CRC64_NVME);
+        assert_eq!(crc.keys()[0], generate_key(64, 128, Crc64Params::CRC64_NVME.poly, true));
+    }
+}
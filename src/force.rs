@@ -0,0 +1,179 @@
+//! Forges an 8-byte window of a buffer so that its CRC-64/NVME checksum equals a chosen
+//! value, analogous to Nayuki's CRC-32 forcer
+//! (<https://www.nayuki.io/page/forcing-a-files-crc-to-any-value>).
+//!
+//! CRC is an affine map over the message: flipping bits in an 8-byte window changes the
+//! final checksum by a value that depends only on which bits were flipped and on how many
+//! bytes follow the window, not on the window's original contents or on anything before it.
+//! That map is linear and invertible, so the required window contents can be recovered by
+//! building its 64x64 bit matrix (one column per bit, via the standard reflected CRC step)
+//! and solving for the window that produces the requested checksum.
+
+use crate::generator::bit_reverse;
+use crate::params::Crc64Params;
+use crate::scalar::reflected_update;
+
+const NVME: Crc64Params = Crc64Params::CRC64_NVME;
+
+/// An 8-byte window could not be forged into `data`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForceCrc64Error {
+    /// `offset + 8` would run past the end of the buffer.
+    WindowOutOfBounds { offset: usize, len: usize },
+}
+
+impl std::fmt::Display for ForceCrc64Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ForceCrc64Error::WindowOutOfBounds { offset, len } => write!(
+                f,
+                "8-byte window at offset {offset} does not fit in a buffer of length {len}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ForceCrc64Error {}
+
+/// Advances the raw (un-init'd, un-xorout'd) reflected CRC-64/NVME register by a slice of bytes.
+fn update(state: u64, data: &[u8]) -> u64 {
+    reflected_update(state, bit_reverse(NVME.poly), data)
+}
+
+/// Solves `sum(columns[i] for i where bit i of x is set) == rhs` for `x`, over GF(2).
+///
+/// `columns` must describe an invertible 64x64 matrix; returns `None` if it turns out to be
+/// singular (which should not happen for a well-formed CRC polynomial).
+fn gf2_solve(columns: &[u64; 64], rhs: u64) -> Option<u64> {
+    // Re-derive the system as 64 equations over the unknown bits, one per output bit `j`:
+    // `sum_i (bit j of columns[i]) * x_i == bit j of rhs`.
+    let mut equations: Vec<(u64, bool)> = (0..64)
+        .map(|j| {
+            let coefficients = (0..64).fold(0u64, |acc, i| acc | (((columns[i] >> j) & 1) << i));
+            (coefficients, (rhs >> j) & 1 == 1)
+        })
+        .collect();
+
+    for unknown in 0..64 {
+        // `unknown` rows are already in reduced form, so the pivot for unknown `unknown` is
+        // always found at or after row `unknown` itself.
+        let pivot = (unknown..equations.len()).find(|&r| (equations[r].0 >> unknown) & 1 == 1)?;
+        equations.swap(unknown, pivot);
+        for r in 0..equations.len() {
+            if r != unknown && (equations[r].0 >> unknown) & 1 == 1 {
+                equations[r].0 ^= equations[unknown].0;
+                equations[r].1 ^= equations[unknown].1;
+            }
+        }
+    }
+
+    Some((0..64).fold(0u64, |x, unknown| {
+        x | ((equations[unknown].1 as u64) << unknown)
+    }))
+}
+
+/// Overwrites the 8 bytes of `data` at `offset` so that the CRC-64/NVME checksum of the
+/// whole buffer becomes `target`.
+///
+/// # Errors
+///
+/// Returns [`ForceCrc64Error::WindowOutOfBounds`] if `offset + 8 > data.len()`.
+///
+/// # Examples
+///
+/// ```
+/// # use crc64fast_nvme::force_crc64;
+/// let mut data = b"Hello, world! Pad me out to 8+ bytes.".to_vec();
+/// force_crc64(&mut data, 0, 0x1122_3344_5566_7788).unwrap();
+/// ```
+pub fn force_crc64(data: &mut [u8], offset: usize, target: u64) -> Result<(), ForceCrc64Error> {
+    let window_end = offset
+        .checked_add(8)
+        .filter(|&end| end <= data.len())
+        .ok_or(ForceCrc64Error::WindowOutOfBounds {
+            offset,
+            len: data.len(),
+        })?;
+
+    let tail = &data[window_end..];
+
+    // Register just after the (real) prefix, with the window itself zeroed out.
+    let state_with_zero_window = update(update(NVME.init, &data[..offset]), &[0u8; 8]);
+    let baseline = update(state_with_zero_window, tail);
+
+    // One column per window bit: the effect that bit alone has on the final register,
+    // isolated from the prefix and from the window's other bits by linearity of the CRC.
+    let zero_contribution = update(0, tail);
+    let mut columns = [0u64; 64];
+    for (bit, column) in columns.iter_mut().enumerate() {
+        let mut window = [0u8; 8];
+        window[bit / 8] |= 1 << (bit % 8);
+        *column = update(update(0, &window), tail) ^ zero_contribution;
+    }
+
+    let target_register = target ^ NVME.xorout;
+    let rhs = target_register ^ baseline;
+    let window = gf2_solve(&columns, rhs).expect("CRC-64/NVME folding matrix is invertible");
+
+    data[offset..window_end].copy_from_slice(&window.to_le_bytes());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crc64_nvme(data: &[u8]) -> u64 {
+        update(NVME.init, data) ^ NVME.xorout
+    }
+
+    #[test]
+    fn check_value_matches_crc64_nvme_spec() {
+        // "123456789" is the standard CRC check string; 0xae8b14860a799888 is the
+        // published CRC-64/NVME check value.
+        assert_eq!(crc64_nvme(b"123456789"), 0xae8b_1486_0a79_9888);
+    }
+
+    #[test]
+    fn forces_window_in_middle_of_buffer() {
+        let mut data = b"Hello, world! This is a test buffer for CRC forcing.".to_vec();
+        let target = 0x1122_3344_5566_7788;
+
+        force_crc64(&mut data, 10, target).unwrap();
+
+        assert_eq!(crc64_nvme(&data), target);
+    }
+
+    #[test]
+    fn forces_window_at_start_of_buffer() {
+        let mut data = vec![0u8; 16];
+        for (i, byte) in data.iter_mut().enumerate().skip(8) {
+            *byte = (i * 7) as u8;
+        }
+        let target = 0x0102_0304_0506_0708;
+
+        force_crc64(&mut data, 0, target).unwrap();
+
+        assert_eq!(crc64_nvme(&data), target);
+    }
+
+    #[test]
+    fn forces_window_at_end_of_buffer() {
+        let mut data = vec![0u8; 16];
+        let target = 0xdead_beef_cafe_babe;
+
+        force_crc64(&mut data, 8, target).unwrap();
+
+        assert_eq!(crc64_nvme(&data), target);
+    }
+
+    #[test]
+    fn rejects_window_past_end_of_buffer() {
+        let mut data = vec![0u8; 4];
+
+        assert_eq!(
+            force_crc64(&mut data, 0, 0),
+            Err(ForceCrc64Error::WindowOutOfBounds { offset: 0, len: 4 })
+        );
+    }
+}
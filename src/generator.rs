@@ -0,0 +1,610 @@
+//! Generates the folding constants (key table, \mu, reciprocal polynomial) needed to
+//! accelerate a CRC-64 computation with the carryless-multiplication technique described in
+//! the "Fast CRC Computation for Generic Polynomials Using PCLMULQDQ Instruction" white paper
+//! from Intel.
+//!
+//! Tested against the ECMA-182 (0x42F0E1EBA9EA3693) as used in CRC-64/XZ and
+//! NVME/Rocksoft (0xAD93D23594C93659) as used in CRC-64/NVME polynomials.
+//!
+//! Derived from: https://github.com/jeffareid/crc/blob/master/crc64r/crc64rg.cpp
+//! With help from: https://github.com/intel/isa-l/issues/88
+//!
+//! Stackoverflow insights:
+//! https://stackoverflow.com/questions/71328336/fast-crc-with-pclmulqdq-not-reflected/71329114#71329114
+//! https://stackoverflow.com/questions/21171733/calculating-constants-for-crc32-using-pclmulqdq
+//!
+//! Linux's implementations: https://github.com/torvalds/linux/blob/786c8248dbd33a5a7a07f7c6e55a7bfc68d2ca48/lib/crc64.c
+//!
+//! [Intel white paper]: https://web.archive.org/web/20131224125630/https://www.intel.com/content/dam/www/public/us/en/documents/white-papers/fast-crc-computation-generic-polynomials-pclmulqdq-paper.pdf
+//!
+//! This module computes folding constants only: [`generate_mu`], [`generate_key`], and
+//! [`generate_reciprocal_polynomial`] all take a `width` so they can target CRC-32 or CRC-16
+//! models as well as CRC-64, but no width-branching reduction routine (the two-CLMUL Barrett
+//! fold a width<64 model needs, as opposed to CRC-64's extra 65th-bit correction) is implemented
+//! anywhere in this crate. Consuming these narrower-width constants in an actual folding
+//! implementation is left to a future accelerated backend.
+
+/// The key sizes to calculate. These are message-side folding distances (in bits) and are the
+/// same across CRC widths; only the polynomial-side constants below depend on `width`.
+pub const KEY_SIZES: [u32; 16] = [
+    128, 192, 256, 320, 384, 448, 512, 576, 640, 704, 768, 832, 896, 960, 1024, 1088,
+];
+
+/// The bit mask covering the low `width` bits of a register.
+///
+/// `width` must be at most 64; `1u64 << 64` would overflow, so the full-width case is handled
+/// separately.
+const fn width_mask(width: u32) -> u64 {
+    if width == 64 {
+        u64::MAX
+    } else {
+        (1u64 << width) - 1
+    }
+}
+
+/// Reverses the bits of a 64-bit unsigned integer.
+///
+/// This function iterates over each bit of the input `u64` value, `f`, from the least significant
+/// bit to the most significant bit,  reversing its order. The reversed bit order is accumulated
+/// in `reversed` and returned. This operation is commonly used in bit manipulation
+/// tasks such as computing reverse CRCs or working with binary protocols.
+///
+/// # Parameters
+///
+/// * `forward`: The 64-bit unsigned integer whose bits are to be reversed.
+///
+/// # Returns
+///
+/// * A `u64` value representing the bit-reversed version of `forward`.
+///
+/// # Examples
+///
+/// ```
+/// use crc64fast_nvme::generator::bit_reverse;
+///
+/// let original: u64 = 1 << 9;
+/// let reversed = bit_reverse(original);
+/// assert_eq!(reversed, 1 << 54);
+/// ```
+///
+/// (Docs generated by GitHub Copilot)
+pub const fn bit_reverse(mut forward: u64) -> u64 {
+    let mut reversed = 0;
+
+    let mut i = 0;
+    while i < 64 {
+        reversed <<= 1;
+        reversed |= forward & 1;
+        forward >>= 1;
+        i += 1;
+    }
+
+    reversed
+}
+
+/// Generates the multiplicative inverse (\mu) for a given polynomial.
+///
+/// This function calculates the multiplicative inverse (\mu) of a given polynomial, which is
+/// used in the Barrett reduction for optimizing the division operation in polynomial arithmetic,
+/// particularly in CRC calculations. The calculation follows the method described in the Intel
+/// white paper on fast CRC computation using the PCLMULQDQ instruction.
+///
+/// The process involves iteratively shifting and XORing values to simulate polynomial division,
+/// with the result being bit-reversed at the end to obtain the final \mu value.
+///
+/// Forward (MSB-first, non-reflected) Barrett reduction is **not** supported by this function.
+/// A correct non-reflected \mu is a genuine `width + 1`-bit quotient with an implicit leading
+/// term (e.g. Intel publishes `0x1_04d1_01df` — 33 bits — for CRC-32/IEEE); this division loop
+/// only ever produces a `width`-bit value, so it cannot represent one. When `reflected` is
+/// `false`, the raw, un-reversed intermediate quotient is returned instead, purely so callers
+/// that must supply a `u64` regardless of CRC direction (such as [`crate::Crc64`]'s informational
+/// getters for a non-reflected model) have a value; it is not a valid Barrett constant for
+/// folding a forward CRC and must not be used as one.
+///
+/// # Parameters
+///
+/// * `width`: The width, in bits, of the CRC (e.g. 64, 32, or 16). The numerator is
+///   conceptually `width + 1` bits wide: for every width, `numerator_low` holds its low `width`
+///   bits and its top bit is tracked separately in `numerator_high` (for `width == 64` that bit
+///   would not fit in a `u64` at all).
+/// * `polynomial`: The polynomial for which the multiplicative inverse is to be calculated.
+///   This is typically the CRC polynomial.
+/// * `reflected`: Whether the result should be bit-reversed for a reflected (refin/refout)
+///   CRC. Passing `false` does not yield a usable forward-domain constant; see above.
+///
+/// # Returns
+///
+/// * The multiplicative inverse (\mu) of the given polynomial as a `u64`.
+///
+/// # Example
+///
+/// ```
+/// use crc64fast_nvme::generator::generate_mu;
+///
+/// let poly = 0xAD93D23594C93659; // CRC-64-NVME polynomial
+/// let mu = generate_mu(64, poly, true);
+/// println!("The multiplicative inverse (mu) for the given polynomial is: {:X}", mu);
+/// ```
+///
+/// (Docs generated by GitHub Copilot)
+pub const fn generate_mu(width: u32, polynomial: u64, reflected: bool) -> u64 {
+    // High part of the numerator, initialized to 1 for division.
+    let mut numerator_high: u64 = 1;
+
+    // Low part of the numerator, starts at 0.
+    let mut numerator_low: u64 = 0;
+
+    // The quotient, initialized to 0.
+    let mut quotient: u64 = 0;
+
+    let mask = width_mask(width);
+
+    let mut i = 0;
+    while i < width {
+        // Shift the quotient left by 1 bit to make room for the next bit.
+        quotient <<= 1;
+
+        if numerator_high != 0 {
+            // Set the least significant bit of Q if Nhi is not 0.
+            quotient |= 1;
+
+            // Perform the XOR operation as part of the division.
+            numerator_low ^= polynomial;
+        }
+        // Update Nhi to the most significant bit of Nlo. For width == 64 this reads the bit
+        // that just overflowed out of the u64; for narrower widths it reads bit `width - 1`
+        // before that bit is masked away below.
+        numerator_high = (numerator_low >> (width - 1)) & 1;
+
+        // Shift Nlo left by 1 bit for the next iteration, discarding anything past `width` bits.
+        numerator_low = (numerator_low << 1) & mask;
+
+        i += 1;
+    }
+
+    // Bit-reverse the quotient to get the final \(\mu\) constant, unless the caller wants the
+    // forward (non-reflected) value. `bit_reverse` always reverses a full 64-bit word, so a
+    // `width`-bit quotient (held in the low bits) ends up in the high bits; shifting back down
+    // by `64 - width` recovers its `width`-bit reversal.
+    if reflected {
+        bit_reverse(quotient) >> (64 - width)
+    } else {
+        quotient
+    }
+}
+
+/// Generates a key for a given polynomial and exponent.
+///
+/// This function computes a key for polynomial-based operations, such as CRC calculations,
+/// using a specified polynomial and exponent. The key generation involves bit manipulation
+/// and arithmetic operations that simulate the polynomial division process. The result is
+/// then bit-reversed to obtain the final key value. This function incorporates Rust's
+/// `wrapping_sub` method to safely handle underflow conditions that can occur during the
+/// subtraction operation.
+///
+/// Forward (MSB-first, non-reflected) CRC folding is **not** supported by this function. When
+/// `reflected` is `false`, the raw, un-reversed remainder of the loop below is returned, which is
+/// `x^(exponent - 1) mod P(x)` rather than the `x^exponent mod P(x)` a forward folding constant
+/// is; it is returned purely so callers that must supply a `u64` regardless of CRC direction (such as
+/// [`crate::Crc64`]'s informational getters for a non-reflected model) have a value; it is not a
+/// valid folding constant for a forward CRC and must not be used as one.
+///
+/// # Parameters
+///
+/// * `width`: The width, in bits, of the CRC (e.g. 64, 32, or 16).
+/// * `exponent`: The exponent value, representing the degree to which the polynomial is raised.
+///   If `exponent` is less than or equal to `width`, the function returns 0, as the
+///   operation does not produce a meaningful result in such cases.
+/// * `polynomial`: The polynomial used for the key generation. This is typically a CRC polynomial.
+/// * `reflected`: Whether the result should be bit-reversed for a reflected (refin/refout)
+///   CRC. Passing `false` does not yield a usable forward-domain constant; see above.
+///
+/// # Returns
+///
+/// * A `u64` representing the generated key, which is the bit-reversed result of the
+///   polynomial division simulation (or the un-reversed remainder, which is not independently
+///   meaningful, for a forward CRC).
+///
+/// # Examples
+///
+/// ```
+/// use crc64fast_nvme::generator::generate_key;
+///
+/// let poly = 0xAD93D23594C93659; // CRC-64-NVME polynomial
+/// let exponent = 128;
+/// let key = generate_key(64, exponent, poly, true);
+/// println!("Generated key: {:X}", key);
+/// ```
+///
+/// (Docs generated by GitHub Copilot)
+pub const fn generate_key(width: u32, exponent: u64, polynomial: u64, reflected: bool) -> u64 {
+    if exponent <= width as u64 {
+        // Return 0 for exponents at or below `width`, as no key is needed.
+        return 0;
+    }
+
+    // Initialize N with the highest bit set.
+    let mut n = 1u64 << (width - 1);
+
+    let mask = width_mask(width);
+
+    // Adjust exponent to fit a `width`-bit operation.
+    let e = exponent - width as u64;
+
+    let mut i = 0;
+    while i < e {
+        // Shift and XOR if the highest bit is set, discarding anything past `width` bits.
+        let top_bit = (n >> (width - 1)) & 1;
+        n = ((n << 1) ^ (0x00u64.wrapping_sub(top_bit) & polynomial)) & mask;
+        i += 1;
+    }
+
+    // Bit-reverse the result to match a reflected CRC's requirements, unless the caller wants
+    // the forward (non-reflected) value. See [`generate_mu`] for why shifting by `64 - width`
+    // recovers the `width`-bit reversal of a value held in the low bits of a `u64`.
+    if reflected {
+        bit_reverse(n) >> (64 - width)
+    } else {
+        n
+    }
+}
+
+/// Generates the reciprocal polynomial for a given polynomial.
+///
+/// This function calculates the reciprocal polynomial by first reversing the bits of the input
+/// polynomial using the `bit_reverse` function. It then shifts the result to the left by one
+/// position and sets the least significant bit to 1. The reciprocal polynomial is used in certain
+/// CRC calculations and other polynomial arithmetic operations where the inverse representation
+/// of a polynomial is required.
+///
+/// For a forward (MSB-first, non-reflected) CRC, `polynomial` is missing its implicit leading
+/// `x^width` term (as is conventional for a CRC generator polynomial), so that term is added back
+/// at bit `width` before the value is returned. For `width == 64` that term would be bit 64, which
+/// doesn't fit in a `u64`; no correctly-aligned forward reciprocal can be produced at that width,
+/// so the bare `polynomial` is returned instead, and forward Barrett folding is unsupported there.
+///
+/// # Parameters
+///
+/// * `width`: The width, in bits, of the CRC (e.g. 64, 32, or 16).
+/// * `polynomial`: The polynomial for which the reciprocal is to be calculated.
+/// * `reflected`: Whether the result should be bit-reversed for a reflected (refin/refout)
+///   CRC, as opposed to a forward, MSB-first one.
+///
+/// # Returns
+///
+/// * The reciprocal polynomial as a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use crc64fast_nvme::generator::generate_reciprocal_polynomial;
+///
+/// let poly = 0xAD93D23594C93659; // CRC-64-NVME polynomial
+/// let reciprocal = generate_reciprocal_polynomial(64, poly, true);
+/// println!("Reciprocal polynomial: {:X}", reciprocal);
+/// ```
+///
+/// (Docs generated by GitHub Copilot)
+pub const fn generate_reciprocal_polynomial(width: u32, polynomial: u64, reflected: bool) -> u64 {
+    if reflected {
+        ((bit_reverse(polynomial) >> (64 - width)) << 1) | 1
+    } else if width < 64 {
+        (1u64 << width) | polynomial
+    } else {
+        polynomial
+    }
+}
+
+#[cfg(test)]
+mod bit_reverse_tests {
+    use super::*;
+
+    #[test]
+    fn reverses_all_zeros_to_zeros() {
+        assert_eq!(bit_reverse(0), 0);
+    }
+
+    #[test]
+    fn reverses_all_ones_to_ones() {
+        let all_ones: u64 = u64::MAX;
+        assert_eq!(bit_reverse(all_ones), all_ones);
+    }
+
+    #[test]
+    fn reverses_single_bit_at_start() {
+        let input: u64 = 1 << 63; // Most significant bit set
+        let expected: u64 = 1; // Least significant bit set
+        assert_eq!(bit_reverse(input), expected);
+    }
+
+    #[test]
+    fn reverses_single_bit_at_end() {
+        let input: u64 = 1; // Least significant bit set
+        let expected: u64 = 1 << 63; // Most significant bit set
+        assert_eq!(bit_reverse(input), expected);
+    }
+
+    #[test]
+    fn reverses_alternating_bits() {
+        let input: u64 = 0b1010101010101010101010101010101010101010101010101010101010101010;
+        let expected: u64 = 0b0101010101010101010101010101010101010101010101010101010101010101;
+        assert_eq!(bit_reverse(input), expected);
+    }
+
+    #[test]
+    fn reverses_example_polynomial() {
+        let input: u64 = 0xAD93D23594C93659;
+        let expected: u64 = 0x9a6c9329ac4bc9b5;
+        let output: u64 = bit_reverse(input);
+
+        println!("Expected {output:#x}");
+
+        assert_eq!(bit_reverse(input), expected);
+    }
+}
+
+#[cfg(test)]
+mod generate_mu_tests {
+    use super::*;
+
+    #[test]
+    fn calculates_mu_for_known_crc64_ecma_polynomial() {
+        let poly = 0x42F0E1EBA9EA3693; // Known CRC-64/XZ polynomial
+        let expected_mu = 0x9c3e466c172963d5; // Expected mu for the given polynomial
+        assert_eq!(generate_mu(64, poly, true), expected_mu);
+    }
+
+    #[test]
+    fn calculates_mu_for_known_crc64_nvme_polynomial() {
+        let poly = 0xAD93D23594C93659; // Known CRC-64/NVME polynomial
+        let expected_mu = 0x27ecfa329aef9f77; // Expected mu for the given polynomial
+        assert_eq!(generate_mu(64, poly, true), expected_mu);
+    }
+
+    #[test]
+    fn calculates_mu_for_known_crc32_ieee_polynomial() {
+        let poly = 0x04C11DB7; // Known CRC-32/IEEE polynomial
+        let expected_mu = 0xf7011641; // Expected mu for the given polynomial
+        assert_eq!(generate_mu(32, poly, true), expected_mu);
+    }
+
+    #[test]
+    fn calculates_mu_for_known_crc16_kermit_polynomial() {
+        let poly = 0x1021; // Known CRC-16/KERMIT polynomial (reflected; not to be confused with the non-reflected CRC-16/XMODEM)
+        let expected_mu = 0x1911; // Expected mu for the given polynomial
+        assert_eq!(generate_mu(16, poly, true), expected_mu);
+    }
+}
+
+#[cfg(test)]
+mod generate_key_tests {
+    use super::*;
+
+    #[test]
+    fn generates_key_for_valid_exponent_and_polynomial_nvme() {
+        static CASES: &[(u64, u64)] = &[
+            (128, 0x21e9761e252621ac),
+            (192, 0xeadc_41fd_2ba3_d420),
+            (256, 0xe1e0_bb9d_45d7_a44c),
+            (320, 0xb0bc_2e58_9204_f500),
+            (384, 0xa3ff_dc1f_e8e8_2a8b),
+            (448, 0xbdd7_ac0e_e1a4_a0f0),
+            (512, 0x6224_2240_ace5_045a),
+            (576, 0x0c32_cdb3_1e18_a84a),
+            (640, 0x0336_3823_e6e7_91e5),
+            (704, 0x7b0a_b10d_d0f8_09fe),
+            (768, 0x34f5_a24e_22d6_6e90),
+            (832, 0x3c25_5f5e_bc41_4423),
+            (896, 0x9465_8840_3d4a_dcbc),
+            (960, 0xd083_dd59_4d96_319d),
+            (1024, 0x5f85_2fb6_1e8d_92dc),
+            (1088, 0xa1ca681e733f9c40),
+        ];
+
+        let poly = 0xAD93D23594C93659; // Known CRC-64/NVME polynomial
+
+        for (exponent, result) in CASES {
+            assert_eq!(generate_key(64, *exponent, poly, true), *result);
+        }
+    }
+
+    #[test]
+    fn generates_key_for_valid_exponent_and_polynomial_ecma() {
+        static CASES: &[(u64, u64)] = &[
+            (128, 0xdabe_95af_c787_5f40),
+            (192, 0xe05d_d497_ca39_3ae4),
+            (256, 0x3be6_53a3_0fe1_af51),
+            (320, 0x6009_5b00_8a9e_fa44),
+            (384, 0x69a3_5d91_c373_0254),
+            (448, 0xb5ea_1af9_c013_aca4),
+            (512, 0x081f_6054_a784_2df4),
+            (576, 0x6ae3_efbb_9dd4_41f3),
+            (640, 0x0e31_d519_421a_63a5),
+            (704, 0x2e30_2032_12ca_c325),
+            (768, 0xe4ce_2cd5_5fea_0037),
+            (832, 0x2fe3_fd29_20ce_82ec),
+            (896, 0x9478_74de_5950_52cb),
+            (960, 0x9e73_5cb5_9b47_24da),
+            (1024, 0xd7d8_6b2a_f73d_e740),
+            (1088, 0x8757_d71d_4fcc_1000),
+        ];
+
+        let poly = 0x42F0E1EBA9EA3693; // Known CRC-64/XZ ECMA-182 polynomial
+
+        for (exponent, result) in CASES {
+            assert_eq!(generate_key(64, *exponent, poly, true), *result);
+        }
+    }
+
+    #[test]
+    fn returns_zero_for_exponents_at_or_below_the_width_regardless_of_direction() {
+        let poly = 0xAD93D23594C93659;
+        assert_eq!(generate_key(64, 64, poly, true), 0);
+        assert_eq!(generate_key(64, 64, poly, false), 0);
+        assert_eq!(generate_key(64, 32, poly, true), 0);
+        assert_eq!(generate_key(64, 32, poly, false), 0);
+    }
+
+    #[test]
+    fn generates_key_for_crc32_ieee_polynomial() {
+        let poly = 0x04C11DB7; // Known CRC-32/IEEE polynomial
+        let expected_reflected_k128 = 0x9ba54c6f;
+        assert_eq!(generate_key(32, 128, poly, true), expected_reflected_k128);
+        assert_eq!(generate_key(32, 32, poly, true), 0);
+    }
+
+    #[test]
+    fn generates_key_for_crc16_kermit_polynomial() {
+        let poly = 0x1021; // Known CRC-16/KERMIT polynomial (reflected; not to be confused with the non-reflected CRC-16/XMODEM)
+        let expected_reflected_k128 = 0x7eea;
+        assert_eq!(generate_key(16, 128, poly, true), expected_reflected_k128);
+        assert_eq!(generate_key(16, 16, poly, true), 0);
+    }
+}
+
+#[cfg(test)]
+mod generate_reciprocal_polynomial_tests {
+    use super::*;
+
+    #[test]
+    fn reciprocal_of_specific_polynomial_nvme() {
+        let poly = 0xAD93D23594C93659; // Known CRC-64/NVME polynomial
+        let expected = 0x34d9_2653_5897_936b; // Expected reciprocal
+        assert_eq!(generate_reciprocal_polynomial(64, poly, true), expected);
+    }
+
+    #[test]
+    fn reciprocal_of_specific_polynomial_ecma() {
+        let poly = 0x42F0E1EBA9EA3693; // Known CRC-64/XZ ECMA-182 polynomial
+        let expected = 0x92d8_af2b_af0e_1e85; // Expected reciprocal
+        assert_eq!(generate_reciprocal_polynomial(64, poly, true), expected);
+    }
+
+    #[test]
+    fn forward_reciprocal_at_width_64_is_the_polynomial_itself() {
+        // The implicit x^64 leading term has nowhere to go in a u64, so width-64 forward
+        // folding is unsupported and the bare polynomial is returned unchanged.
+        let poly = 0xAD93D23594C93659;
+        assert_eq!(generate_reciprocal_polynomial(64, poly, false), poly);
+    }
+
+    #[test]
+    fn forward_reciprocal_below_width_64_restores_the_implicit_leading_term() {
+        let poly = 0x04C11DB7; // Known CRC-32/IEEE polynomial
+        let expected = (1u64 << 32) | poly;
+        assert_eq!(generate_reciprocal_polynomial(32, poly, false), expected);
+    }
+
+    #[test]
+    fn reciprocal_of_crc32_ieee_polynomial() {
+        // Matches the well-known Barrett-reduction constant for CRC-32/IEEE.
+        let poly = 0x04C11DB7;
+        let expected = 0x1_db71_0641;
+        assert_eq!(generate_reciprocal_polynomial(32, poly, true), expected);
+    }
+}
+
+#[cfg(test)]
+mod width_generalization_round_trip_tests {
+    use super::*;
+
+    /// A reference, bit-at-a-time reflected CRC, generic over `width`, independent of
+    /// [`generate_key`]/[`generate_mu`]. Used only to confirm that this module's `width`
+    /// generalization targets the same polynomial orientation real-world CRC-32/CRC-16 models
+    /// expect, by reproducing their published check values end to end. This does **not** exercise
+    /// [`generate_key`] or [`generate_mu`] themselves; see the
+    /// `generated_*_key_matches_independent_modular_exponentiation` tests below for a check of
+    /// [`generate_key`].
+    fn reference_reflected_crc(width: u32, poly: u64, init: u64, xorout: u64, data: &[u8]) -> u64 {
+        let poly_rev = bit_reverse(poly) >> (64 - width);
+        let mut register = init;
+        for &byte in data {
+            register ^= byte as u64;
+            for _ in 0..8 {
+                register = if register & 1 == 1 {
+                    (register >> 1) ^ poly_rev
+                } else {
+                    register >> 1
+                };
+            }
+        }
+        register ^ xorout
+    }
+
+    #[test]
+    fn crc32_ieee_check_value_round_trips_at_width_32() {
+        let poly = 0x04C11DB7; // Known CRC-32/IEEE polynomial
+        let crc = reference_reflected_crc(32, poly, 0xFFFF_FFFF, 0xFFFF_FFFF, b"123456789");
+        assert_eq!(crc, 0xCBF4_3926); // Published CRC-32/IEEE check value
+    }
+
+    #[test]
+    fn crc16_kermit_check_value_round_trips_at_width_16() {
+        let poly = 0x1021; // Known CRC-16/KERMIT polynomial
+        let crc = reference_reflected_crc(16, poly, 0x0000, 0x0000, b"123456789");
+        assert_eq!(crc, 0x2189); // Published CRC-16/KERMIT check value
+    }
+
+    /// Carry-less multiplication of two GF(2) polynomials, each up to 64 bits wide.
+    fn gf2_carryless_multiply(a: u64, b: u64) -> u128 {
+        let mut product: u128 = 0;
+        for bit in 0..64 {
+            if (b >> bit) & 1 == 1 {
+                product ^= (a as u128) << bit;
+            }
+        }
+        product
+    }
+
+    /// Reduces a GF(2) polynomial of degree at most `2 * width - 2` modulo the degree-`width`
+    /// monic divisor `x^width + polynomial`, by repeatedly cancelling the current top bit.
+    fn gf2_reduce(mut value: u128, width: u32, polynomial: u64) -> u64 {
+        for bit in (width..=(2 * width - 2)).rev() {
+            if (value >> bit) & 1 == 1 {
+                value ^= (polynomial as u128) << (bit - width);
+                value ^= 1u128 << bit;
+            }
+        }
+        value as u64
+    }
+
+    /// Computes `x^exponent mod P(x)` by binary exponentiation (square-and-multiply) over GF(2),
+    /// where `P(x) = x^width + polynomial`. This is a different algorithm from [`generate_key`]'s
+    /// shift-and-conditionally-XOR loop, so matching its output is genuine independent evidence
+    /// the generated constant is correct, rather than a tautology.
+    fn gf2_pow_x_mod(width: u32, polynomial: u64, exponent: u64) -> u64 {
+        let mut result: u64 = 1; // x^0
+        let mut base: u64 = 2; // x^1
+        let mut remaining_exponent = exponent;
+        while remaining_exponent > 0 {
+            if remaining_exponent & 1 == 1 {
+                result = gf2_reduce(gf2_carryless_multiply(result, base), width, polynomial);
+            }
+            base = gf2_reduce(gf2_carryless_multiply(base, base), width, polynomial);
+            remaining_exponent >>= 1;
+        }
+        result
+    }
+
+    /// [`generate_key`]'s forward (`reflected = false`) loop starts from `x^(width - 1)` and
+    /// takes `exponent - width` reduction steps, so its raw remainder is `x^(exponent - 1) mod
+    /// P(x)`; the reflected output is that remainder's bit reversal. Checking this against
+    /// [`gf2_pow_x_mod`] ties the generated constant to a genuinely independent derivation,
+    /// unlike [`reference_reflected_crc`] above (which never calls [`generate_key`] at all).
+    fn expected_reflected_key(width: u32, exponent: u64, polynomial: u64) -> u64 {
+        let forward_remainder = gf2_pow_x_mod(width, polynomial, exponent - 1);
+        bit_reverse(forward_remainder) >> (64 - width)
+    }
+
+    #[test]
+    fn generated_crc32_ieee_key_matches_independent_modular_exponentiation() {
+        let poly = 0x04C11DB7; // Known CRC-32/IEEE polynomial
+        assert_eq!(generate_key(32, 128, poly, true), expected_reflected_key(32, 128, poly));
+    }
+
+    #[test]
+    fn generated_crc16_kermit_key_matches_independent_modular_exponentiation() {
+        let poly = 0x1021; // Known CRC-16/KERMIT polynomial
+        assert_eq!(generate_key(16, 128, poly, true), expected_reflected_key(16, 128, poly));
+    }
+}
@@ -0,0 +1,21 @@
+//! crc64fast-nvme: CRC-64 checksums, plus supporting tooling for working with them (such as
+//! forging a checksum into an existing buffer) and for generating the folding constants an
+//! accelerated, carryless-multiplication implementation would need.
+//!
+//! [`Crc64::digest`] currently computes checksums via the portable, bit-at-a-time scalar
+//! fallback in [`scalar`]; no SIMD/CLMUL-accelerated folding path is implemented in this crate
+//! yet. [`generator`] and [`define_crc64!`] precompute the constants (key table, \mu, reciprocal
+//! polynomial) such a path would consume.
+
+pub mod generator;
+pub mod scalar;
+
+mod crc64;
+mod force;
+mod macros;
+mod params;
+
+pub use crc64::Crc64;
+pub use force::force_crc64;
+pub use force::ForceCrc64Error;
+pub use params::Crc64Params;
@@ -0,0 +1,142 @@
+//! The [`define_crc64!`] macro: generates a zero-overhead CRC-64 struct with its folding
+//! constants and self-test check value baked in at compile time, rather than computed at
+//! runtime as [`crate::Crc64::with_params`] does.
+
+/// Defines a zero-sized struct implementing a CRC-64 model, with its folding-constant table,
+/// \mu, reciprocal polynomial, and `b"123456789"` self-test check value all computed at compile
+/// time via the `const fn` generator in [`crate::generator`].
+///
+/// # Examples
+///
+/// ```
+/// crc64fast_nvme::define_crc64!(
+///     MyCrc64Nvme,
+///     poly = 0xAD93_D235_94C9_3659,
+///     init = 0xFFFF_FFFF_FFFF_FFFF,
+///     xorout = 0xFFFF_FFFF_FFFF_FFFF,
+///     refin = true
+/// );
+///
+/// assert_eq!(MyCrc64Nvme::CHECK, 0xae8b_1486_0a79_9888);
+/// assert_eq!(MyCrc64Nvme::digest(b"123456789"), MyCrc64Nvme::CHECK);
+/// ```
+#[macro_export]
+macro_rules! define_crc64 {
+    ($name:ident, poly = $poly:expr, init = $init:expr, xorout = $xorout:expr, refin = $refin:expr) => {
+        /// A CRC-64 model with its folding constants computed at compile time by
+        /// [`crc64fast_nvme::define_crc64!`](crc64fast_nvme::define_crc64).
+        pub struct $name;
+
+        impl $name {
+            /// The parameters this model was defined with.
+            pub const PARAMS: $crate::Crc64Params = $crate::Crc64Params {
+                poly: $poly,
+                init: $init,
+                xorout: $xorout,
+                refin: $refin,
+                refout: $refin,
+            };
+
+            /// The precomputed folding-constant table, indexed the same way as
+            /// [`crc64fast_nvme::generator::KEY_SIZES`](crc64fast_nvme::generator::KEY_SIZES).
+            pub const KEYS: [u64; $crate::generator::KEY_SIZES.len()] = {
+                let mut keys = [0u64; $crate::generator::KEY_SIZES.len()];
+                let mut i = 0;
+                while i < $crate::generator::KEY_SIZES.len() {
+                    keys[i] = $crate::generator::generate_key(
+                        64,
+                        $crate::generator::KEY_SIZES[i] as u64,
+                        $poly,
+                        $refin,
+                    );
+                    i += 1;
+                }
+                keys
+            };
+
+            /// The precomputed Barrett-reduction \mu constant.
+            pub const MU: u64 = $crate::generator::generate_mu(64, $poly, $refin);
+
+            /// The precomputed reciprocal polynomial.
+            pub const RECIPROCAL: u64 =
+                $crate::generator::generate_reciprocal_polynomial(64, $poly, $refin);
+
+            /// The CRC of `b"123456789"`, the standard self-test check value for this model.
+            pub const CHECK: u64 = Self::digest(b"123456789");
+
+            /// Computes the CRC-64 checksum of `data` under this model.
+            pub const fn digest(data: &[u8]) -> u64 {
+                let register = if $refin {
+                    let poly_rev = $crate::generator::bit_reverse($poly);
+                    $crate::scalar::reflected_update($init, poly_rev, data)
+                } else {
+                    $crate::scalar::forward_update($init, $poly, data)
+                };
+
+                register ^ $xorout
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    crate::define_crc64!(
+        Nvme,
+        poly = 0xAD93_D235_94C9_3659,
+        init = 0xFFFF_FFFF_FFFF_FFFF,
+        xorout = 0xFFFF_FFFF_FFFF_FFFF,
+        refin = true
+    );
+
+    crate::define_crc64!(
+        Xz,
+        poly = 0x42F0_E1EB_A9EA_3693,
+        init = 0xFFFF_FFFF_FFFF_FFFF,
+        xorout = 0xFFFF_FFFF_FFFF_FFFF,
+        refin = true
+    );
+
+    #[test]
+    fn check_value_matches_crc64_nvme_spec() {
+        assert_eq!(Nvme::CHECK, 0xae8b_1486_0a79_9888);
+        assert_eq!(Nvme::digest(b"123456789"), Nvme::CHECK);
+    }
+
+    #[test]
+    fn check_value_matches_crc64_xz_spec() {
+        assert_eq!(Xz::CHECK, 0x995d_c9bb_df19_39fa);
+    }
+
+    #[test]
+    fn params_match_the_macro_arguments() {
+        assert_eq!(Nvme::PARAMS, crate::Crc64Params::CRC64_NVME);
+        assert_eq!(Xz::PARAMS, crate::Crc64Params::CRC64_XZ);
+    }
+
+    #[test]
+    fn key_table_matches_generator_output() {
+        assert_eq!(
+            Nvme::KEYS[0],
+            crate::generator::generate_key(64, 128, Nvme::PARAMS.poly, true)
+        );
+        assert_eq!(
+            Xz::KEYS[0],
+            crate::generator::generate_key(64, 128, Xz::PARAMS.poly, true)
+        );
+    }
+
+    #[test]
+    fn mu_and_reciprocal_match_generator_output() {
+        assert_eq!(Nvme::MU, crate::generator::generate_mu(64, Nvme::PARAMS.poly, true));
+        assert_eq!(
+            Nvme::RECIPROCAL,
+            crate::generator::generate_reciprocal_polynomial(64, Nvme::PARAMS.poly, true)
+        );
+        assert_eq!(Xz::MU, crate::generator::generate_mu(64, Xz::PARAMS.poly, true));
+        assert_eq!(
+            Xz::RECIPROCAL,
+            crate::generator::generate_reciprocal_polynomial(64, Xz::PARAMS.poly, true)
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Runtime description of a CRC-64 model, for use with [`crate::Crc64::with_params`].
+
+/// The parameters that define a CRC-64 model: its generator polynomial, initial register
+/// value, final XOR value, and bit order, in the same terms as the
+/// [Rocksoft CRC catalogue](https://reveng.sourceforge.io/crc-catalogue/17plus.htm).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crc64Params {
+    /// The generator polynomial, with the implicit leading `x^64` term dropped.
+    pub poly: u64,
+    /// The register value before any input is processed.
+    pub init: u64,
+    /// The value XORed into the register to produce the final checksum.
+    pub xorout: u64,
+    /// Whether input bytes are reflected before being fed into the register.
+    pub refin: bool,
+    /// Whether the register is reflected before `xorout` is applied.
+    pub refout: bool,
+}
+
+impl Crc64Params {
+    /// CRC-64/NVME, a.k.a. Rocksoft, as used by the NVM Express base specification.
+    pub const CRC64_NVME: Crc64Params = Crc64Params {
+        poly: 0xad93_d235_94c9_3659,
+        init: 0xffff_ffff_ffff_ffff,
+        xorout: 0xffff_ffff_ffff_ffff,
+        refin: true,
+        refout: true,
+    };
+
+    /// CRC-64/XZ, the reflected variant of the ECMA-182 polynomial, as used by the `.xz` file
+    /// format. See [`Crc64Params::CRC64_ECMA_182`] for the non-reflected variant.
+    pub const CRC64_XZ: Crc64Params = Crc64Params {
+        poly: 0x42f0_e1eb_a9ea_3693,
+        init: 0xffff_ffff_ffff_ffff,
+        xorout: 0xffff_ffff_ffff_ffff,
+        refin: true,
+        refout: true,
+    };
+
+    /// CRC-64/ECMA-182, the non-reflected variant of the ECMA-182 polynomial. See
+    /// [`Crc64Params::CRC64_XZ`] for the reflected variant used by the `.xz` format.
+    pub const CRC64_ECMA_182: Crc64Params = Crc64Params {
+        poly: 0x42f0_e1eb_a9ea_3693,
+        init: 0x0000_0000_0000_0000,
+        xorout: 0x0000_0000_0000_0000,
+        refin: false,
+        refout: false,
+    };
+}
@@ -0,0 +1,99 @@
+//! Portable, non-accelerated CRC-64 register stepping, shared by [`crate::Crc64`]'s fallback
+//! digest path, by [`crate::force_crc64`]'s forcing algorithm, and by the
+//! [`crate::define_crc64`] macro's generated `digest` method.
+//!
+//! These are `const fn`s so that `define_crc64!` can evaluate its `CHECK` associated const at
+//! compile time.
+
+/// Advances a reflected (LSB-first) CRC-64 register by one byte.
+///
+/// `poly_rev` is the bit-reversed generator polynomial, as produced by
+/// [`crate::generator::bit_reverse`].
+pub const fn reflected_step(state: u64, byte: u8, poly_rev: u64) -> u64 {
+    let mut state = state ^ (byte as u64);
+    let mut i = 0;
+    while i < 8 {
+        state = if state & 1 == 1 {
+            (state >> 1) ^ poly_rev
+        } else {
+            state >> 1
+        };
+        i += 1;
+    }
+    state
+}
+
+/// Advances a reflected CRC-64 register by a whole slice of bytes.
+pub const fn reflected_update(mut state: u64, poly_rev: u64, data: &[u8]) -> u64 {
+    let mut i = 0;
+    while i < data.len() {
+        state = reflected_step(state, data[i], poly_rev);
+        i += 1;
+    }
+    state
+}
+
+/// Advances a forward (MSB-first, non-reflected) CRC-64 register by one byte.
+pub const fn forward_step(state: u64, byte: u8, poly: u64) -> u64 {
+    let mut state = state ^ ((byte as u64) << 56);
+    let mut i = 0;
+    while i < 8 {
+        state = if state & (1u64 << 63) != 0 {
+            (state << 1) ^ poly
+        } else {
+            state << 1
+        };
+        i += 1;
+    }
+    state
+}
+
+/// Advances a forward CRC-64 register by a whole slice of bytes.
+pub const fn forward_update(mut state: u64, poly: u64, data: &[u8]) -> u64 {
+    let mut i = 0;
+    while i < data.len() {
+        state = forward_step(state, data[i], poly);
+        i += 1;
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params::Crc64Params;
+
+    #[test]
+    fn reflected_update_matches_crc64_nvme_check_value() {
+        let poly_rev = crate::generator::bit_reverse(Crc64Params::CRC64_NVME.poly);
+        let register = reflected_update(Crc64Params::CRC64_NVME.init, poly_rev, b"123456789");
+        assert_eq!(
+            register ^ Crc64Params::CRC64_NVME.xorout,
+            0xae8b_1486_0a79_9888
+        );
+    }
+
+    #[test]
+    fn reflected_update_is_linear_in_same_length_messages() {
+        let poly_rev = crate::generator::bit_reverse(Crc64Params::CRC64_NVME.poly);
+        let a = b"abcdefgh";
+        let b = b"01234567";
+        let xored: Vec<u8> = a.iter().zip(b.iter()).map(|(x, y)| x ^ y).collect();
+
+        let lhs = reflected_update(0, poly_rev, a) ^ reflected_update(0, poly_rev, b);
+        let rhs = reflected_update(0, poly_rev, &xored);
+        assert_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn forward_update_is_linear_in_same_length_messages() {
+        let poly = Crc64Params::CRC64_XZ.poly;
+        let a = b"abcdefgh";
+        let b = b"01234567";
+        let xored: Vec<u8> = a.iter().zip(b.iter()).map(|(x, y)| x ^ y).collect();
+
+        let lhs = forward_update(0, poly, a) ^ forward_update(0, poly, b);
+        let rhs = forward_update(0, poly, &xored);
+        assert_eq!(lhs, rhs);
+    }
+}